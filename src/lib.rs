@@ -1,5 +1,8 @@
+use std::collections::HashSet;
+
 use anchor_lang::{AnchorDeserialize, prelude::ProgramError};
 use borsh::BorshDeserialize;
+use solana_address_lookup_table_program::state::AddressLookupTable;
 use solana_banks_interface::{BanksTransactionResultWithSimulation, TransactionStatus};
 use solana_program::program_pack::Pack;
 use solana_program_test::{
@@ -7,22 +10,28 @@ use solana_program_test::{
 };
 use solana_sdk::{
     account::Account,
+    address_lookup_table_account::AddressLookupTableAccount,
     clock::Clock,
     compute_budget,
     genesis_config::GenesisConfig,
     instruction::{Instruction, InstructionError},
+    message::{v0, VersionedMessage},
     native_token::LAMPORTS_PER_SOL,
     program_pack::IsInitialized,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
     signer::Signer,
     system_instruction,
-    transaction::{Transaction, TransactionError},
+    transaction::{Transaction, TransactionError, VersionedTransaction},
 };
 
 // Assume that ProgramSimulator is defined as before:
 pub struct ProgramSimulator {
     pub program_test_context: ProgramTestContext,
+    // Set by `warp_to_slot`/`warp_to_epoch`/`advance_clock_*` to mark
+    // `last_blockhash` as stale, since `get_new_latest_blockhash` can fail or
+    // hang when polled against a blockhash from before a warp.
+    blockhash_dirty: bool,
     // other fields...
 }
 
@@ -33,53 +42,74 @@ impl ProgramSimulator {
 
         ProgramSimulator {
             program_test_context,
+            blockhash_dirty: false,
         }
     }
 
-    /// Common helper to build and sign a transaction with default compute limit.
+    /// Refresh `last_blockhash` before building a transaction. If a warp or
+    /// clock advance has made the cached blockhash stale, fetch the bank's
+    /// current blockhash directly instead of polling for a *new* one, which
+    /// can fail or hang when the poll is seeded with a pre-warp blockhash.
+    async fn refresh_blockhash(&mut self) -> Result<(), BanksClientError> {
+        let blockhash = if self.blockhash_dirty {
+            let blockhash = self
+                .program_test_context
+                .banks_client
+                .get_latest_blockhash()
+                .await?;
+            self.blockhash_dirty = false;
+            blockhash
+        } else {
+            self.program_test_context
+                .banks_client
+                .get_new_latest_blockhash(&self.program_test_context.last_blockhash)
+                .await?
+        };
+
+        self.program_test_context.last_blockhash = blockhash;
+        Ok(())
+    }
+
+    /// Build and sign a transaction out of exactly the given instructions,
+    /// without injecting a compute budget instruction. Callers that want the
+    /// historical default compute limit use the `_with_default_compute_limit`
+    /// methods below, which prepend it before delegating here; anything else
+    /// (e.g. `TransactionBuilder`) is free to manage its own compute budget.
     async fn build_and_sign_tx(
         &mut self,
         instructions: &[Instruction],
-        signers: &[&Keypair],
-        payer: Option<&Keypair>,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
     ) -> Result<Transaction, BanksClientError> {
-        // Create the compute budget instruction.
-        let compute_units_ix =
-            compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(2_000_000);
-
-        // Combine instructions.
-        let mut all_instructions = Vec::with_capacity(instructions.len() + 1);
-        all_instructions.push(compute_units_ix);
-        all_instructions.extend_from_slice(instructions);
-
         // Determine the actual payer.
         let actual_payer = payer.unwrap_or(&self.program_test_context.payer);
 
         // Create the transaction with the payer.
-        let mut transaction =
-            Transaction::new_with_payer(&all_instructions, Some(&actual_payer.pubkey()));
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&actual_payer.pubkey()));
 
-        // Get a new blockhash, propagating errors instead of panicking.
-        let blockhash = self
-            .program_test_context
-            .banks_client
-            .get_new_latest_blockhash(&self.program_test_context.last_blockhash)
-            .await?;
-        self.program_test_context.last_blockhash = blockhash;
+        self.refresh_blockhash().await?;
 
-        // Partially sign with the payer, then additional signers.
-        transaction.partial_sign(&[actual_payer], self.program_test_context.last_blockhash);
-        transaction.partial_sign(signers, self.program_test_context.last_blockhash);
+        // Partially sign with the payer, then additional signers, propagating
+        // signing errors instead of panicking (e.g. a `Signer` impl backed by a
+        // remote or hardware wallet that fails to produce a signature).
+        transaction
+            .try_partial_sign(&[actual_payer], self.program_test_context.last_blockhash)
+            .map_err(|_| BanksClientError::ClientError("Failed to sign transaction with payer"))?;
+        transaction
+            .try_partial_sign(signers, self.program_test_context.last_blockhash)
+            .map_err(|_| BanksClientError::ClientError("Failed to sign transaction with signers"))?;
 
         Ok(transaction)
     }
 
-    /// Process one or more instructions (transaction is sent on-chain).
-    pub async fn process_ixs_with_default_compute_limit(
+    /// Process one or more instructions exactly as given (transaction is sent
+    /// on-chain). Unlike `process_ixs_with_default_compute_limit`, no compute
+    /// budget instruction is injected; use `TransactionBuilder` if you want one.
+    pub async fn process_ixs(
         &mut self,
         instructions: &[Instruction],
-        signers: &[&Keypair],
-        payer: Option<&Keypair>,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
     ) -> Result<Signature, BanksClientError> {
         let transaction = self.build_and_sign_tx(instructions, signers, payer).await?;
         let signature = transaction.signatures[0];
@@ -90,23 +120,23 @@ impl ProgramSimulator {
         Ok(signature)
     }
 
-    /// Process a single instruction by wrapping it and calling the multi-instruction version.
-    pub async fn process_ix_with_default_compute_limit(
+    /// Process a single instruction by wrapping it and calling `process_ixs`.
+    pub async fn process_ix(
         &mut self,
         instruction: Instruction,
-        signers: &[&Keypair],
-        payer: Option<&Keypair>,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
     ) -> Result<Signature, BanksClientError> {
-        self.process_ixs_with_default_compute_limit(&[instruction], signers, payer)
-            .await
+        self.process_ixs(&[instruction], signers, payer).await
     }
 
-    /// Simulate one or more instructions (without committing the transaction).
-    pub async fn simulate_ixs_with_default_compute_limit(
+    /// Simulate one or more instructions exactly as given (without committing
+    /// the transaction or injecting a compute budget instruction).
+    pub async fn simulate_ixs(
         &mut self,
         instructions: &[Instruction],
-        signers: &[&Keypair],
-        payer: Option<&Keypair>,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
     ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
         let transaction = self.build_and_sign_tx(instructions, signers, payer).await?;
         self.program_test_context
@@ -115,12 +145,65 @@ impl ProgramSimulator {
             .await
     }
 
+    /// Simulate a single instruction by wrapping it and calling `simulate_ixs`.
+    pub async fn simulate_ix(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+    ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
+        self.simulate_ixs(&[instruction], signers, payer).await
+    }
+
+    /// Process one or more instructions (transaction is sent on-chain), with
+    /// the compute unit limit raised to 2,000,000.
+    pub async fn process_ixs_with_default_compute_limit(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+    ) -> Result<Signature, BanksClientError> {
+        self.process_ixs(
+            &with_default_compute_limit(instructions),
+            signers,
+            payer,
+        )
+        .await
+    }
+
+    /// Process a single instruction by wrapping it and calling the multi-instruction version.
+    pub async fn process_ix_with_default_compute_limit(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+    ) -> Result<Signature, BanksClientError> {
+        self.process_ixs_with_default_compute_limit(&[instruction], signers, payer)
+            .await
+    }
+
+    /// Simulate one or more instructions (without committing the transaction),
+    /// with the compute unit limit raised to 2,000,000.
+    pub async fn simulate_ixs_with_default_compute_limit(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+    ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
+        self.simulate_ixs(
+            &with_default_compute_limit(instructions),
+            signers,
+            payer,
+        )
+        .await
+    }
+
     /// Simulate a single instruction.
     pub async fn simulate_ix_with_default_compute_limit(
         &mut self,
         instruction: Instruction,
-        signers: &[&Keypair],
-        payer: Option<&Keypair>,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
     ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
         self.simulate_ixs_with_default_compute_limit(&[instruction], signers, payer)
             .await
@@ -217,6 +300,7 @@ impl ProgramSimulator {
         clock.epoch_start_timestamp += seconds_to_advance;
         clock.unix_timestamp += seconds_to_advance;
         self.program_test_context.set_sysvar(&clock);
+        self.blockhash_dirty = true;
 
         Ok(())
     }
@@ -234,6 +318,7 @@ impl ProgramSimulator {
         clock.epoch_start_timestamp = seconds_to_advance;
         clock.unix_timestamp = seconds_to_advance;
         self.program_test_context.set_sysvar(&clock);
+        self.blockhash_dirty = true;
 
         Ok(())
     }
@@ -250,15 +335,427 @@ impl ProgramSimulator {
 
     pub fn warp_to_epoch(&mut self, warp_epoch: u64) -> Result<(), ProgramTestError> {
         self.program_test_context.warp_to_epoch(warp_epoch)?;
+        self.blockhash_dirty = true;
 
         Ok(())
     }
 
     pub fn warp_to_slot(&mut self, warp_slot: u64) -> Result<(), ProgramTestError> {
         self.program_test_context.warp_to_slot(warp_slot)?;
+        self.blockhash_dirty = true;
 
         Ok(())
     }
+
+    /// Warp to `warp_slot` and then process instructions, refreshing the
+    /// blockhash in between so the submission doesn't race the now-stale one
+    /// cached from before the warp.
+    pub async fn process_after_warp(
+        &mut self,
+        warp_slot: u64,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+    ) -> Result<Signature, BanksClientError> {
+        self.warp_to_slot(warp_slot)
+            .map_err(|_| BanksClientError::ClientError("Failed to warp to slot"))?;
+
+        self.process_ixs_with_default_compute_limit(instructions, signers, payer)
+            .await
+    }
+
+    /// Process one or more instructions and capture the logs, compute units
+    /// consumed, and return data produced along the way, instead of discarding
+    /// everything but the `Signature`.
+    pub async fn process_ixs_capturing(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+    ) -> Result<TransactionOutcome, BanksClientError> {
+        let transaction = self
+            .build_and_sign_tx(&with_default_compute_limit(instructions), signers, payer)
+            .await?;
+        let signature = transaction.signatures[0];
+
+        let result = self
+            .program_test_context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        let (logs, compute_units_consumed, return_data) = match result.metadata {
+            Some(metadata) => (
+                metadata.log_messages,
+                metadata.compute_units_consumed,
+                metadata.return_data.map(|data| data.data),
+            ),
+            None => (Vec::new(), 0, None),
+        };
+
+        Ok(TransactionOutcome {
+            signature,
+            logs,
+            compute_units_consumed,
+            return_data,
+        })
+    }
+
+    /// Process a single instruction and capture its logs, compute units
+    /// consumed, and return data.
+    pub async fn process_ix_capturing(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+    ) -> Result<TransactionOutcome, BanksClientError> {
+        self.process_ixs_capturing(&[instruction], signers, payer)
+            .await
+    }
+
+    /// Fetch and deserialize the on-chain Address Lookup Table accounts for
+    /// the given keys.
+    async fn fetch_lookup_table_accounts(
+        &mut self,
+        lookup_tables: &[Pubkey],
+    ) -> Result<Vec<AddressLookupTableAccount>, BanksClientError> {
+        let mut lookup_table_accounts = Vec::with_capacity(lookup_tables.len());
+
+        for table_key in lookup_tables {
+            let account = self.get_account(*table_key).await?;
+            let table = AddressLookupTable::deserialize(&account.data).map_err(|_| {
+                BanksClientError::ClientError("Invalid address lookup table account")
+            })?;
+
+            lookup_table_accounts.push(AddressLookupTableAccount {
+                key: *table_key,
+                addresses: table.addresses.to_vec(),
+            });
+        }
+
+        Ok(lookup_table_accounts)
+    }
+
+    /// Common helper to build and sign a v0 transaction with default compute
+    /// limit, resolving non-static accounts through the given Address Lookup
+    /// Tables.
+    async fn build_and_sign_v0_tx(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+        lookup_tables: &[Pubkey],
+    ) -> Result<VersionedTransaction, BanksClientError> {
+        // Create the compute budget instruction.
+        let compute_units_ix =
+            compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(2_000_000);
+
+        // Combine instructions.
+        let mut all_instructions = Vec::with_capacity(instructions.len() + 1);
+        all_instructions.push(compute_units_ix);
+        all_instructions.extend_from_slice(instructions);
+
+        // Determine the actual payer.
+        let actual_payer = payer.unwrap_or(&self.program_test_context.payer);
+
+        // Resolve the lookup tables up front so a bad table key surfaces before
+        // we touch the blockhash.
+        let lookup_table_accounts = self.fetch_lookup_table_accounts(lookup_tables).await?;
+
+        // Every non-signer account referenced by the instructions must either
+        // be the payer/a signer/an invoked program id (which have to stay
+        // static regardless) or be present in one of the supplied lookup tables. `try_compile` itself
+        // would happily fall back to an extra static key instead, but that
+        // silently defeats the point of passing lookup tables in the first
+        // place, so fail clearly here instead.
+        validate_accounts_are_resolvable(
+            &all_instructions,
+            &actual_payer.pubkey(),
+            signers,
+            &lookup_table_accounts,
+        )?;
+
+        self.refresh_blockhash().await?;
+
+        // Compile the v0 message. The payer stays the first static key.
+        let message = v0::Message::try_compile(
+            &actual_payer.pubkey(),
+            &all_instructions,
+            &lookup_table_accounts,
+            self.program_test_context.last_blockhash,
+        )
+        .map_err(|_| {
+            BanksClientError::ClientError(
+                "Failed to compile v0 message: too many accounts referenced across static keys and lookup tables",
+            )
+        })?;
+
+        // Partially sign with the payer, then the additional signers, taking
+        // care not to list the payer twice when it's also passed in `signers`
+        // (the normal case): `VersionedTransaction::try_new`, unlike
+        // `Transaction::try_partial_sign`, requires the signer count to match
+        // the message's `num_required_signatures` exactly and errors on
+        // duplicates.
+        let mut all_signers: Vec<&dyn Signer> = Vec::with_capacity(signers.len() + 1);
+        all_signers.push(actual_payer);
+        for signer in signers {
+            if signer.pubkey() != actual_payer.pubkey() {
+                all_signers.push(*signer);
+            }
+        }
+
+        let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), &all_signers)
+            .map_err(|_| BanksClientError::ClientError("Failed to sign versioned transaction"))?;
+
+        Ok(transaction)
+    }
+
+    /// Process one or more instructions as a v0 transaction, resolving
+    /// non-static accounts through the given Address Lookup Tables.
+    pub async fn process_ixs_v0_with_default_compute_limit(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+        lookup_tables: &[Pubkey],
+    ) -> Result<Signature, BanksClientError> {
+        let transaction = self
+            .build_and_sign_v0_tx(instructions, signers, payer, lookup_tables)
+            .await?;
+        let signature = transaction.signatures[0];
+        self.program_test_context
+            .banks_client
+            .process_transaction(transaction)
+            .await?;
+        Ok(signature)
+    }
+
+    /// Simulate one or more instructions as a v0 transaction, resolving
+    /// non-static accounts through the given Address Lookup Tables.
+    pub async fn simulate_ixs_v0_with_default_compute_limit(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+        lookup_tables: &[Pubkey],
+    ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
+        let transaction = self
+            .build_and_sign_v0_tx(instructions, signers, payer, lookup_tables)
+            .await?;
+        self.program_test_context
+            .banks_client
+            .simulate_transaction(transaction)
+            .await
+    }
+
+    /// Process a single instruction and assert it fails with exactly
+    /// `expected`, converted through `into_transaction_error`. Panics with a
+    /// diff of expected vs. actual on any mismatch, including success.
+    pub async fn expect_ix_error(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+        expected: impl Into<anchor_lang::prelude::Error>,
+    ) -> Result<(), BanksClientError> {
+        self.expect_ix_error_with_index(&[instruction], signers, payer, 0, expected)
+            .await
+    }
+
+    /// Like `expect_ix_error`, but processes `instructions` as one transaction
+    /// and expects the instruction at `instruction_index` to fail.
+    pub async fn expect_ix_error_with_index(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&dyn Signer],
+        payer: Option<&dyn Signer>,
+        instruction_index: u8,
+        expected: impl Into<anchor_lang::prelude::Error>,
+    ) -> Result<(), BanksClientError> {
+        let expected_error = into_transaction_error_with_index(instruction_index, expected);
+        let result = self.process_ixs(instructions, signers, payer).await;
+
+        match result {
+            Ok(signature) => panic!(
+                "expected transaction to fail with {:?}, but it succeeded with signature {}",
+                expected_error, signature
+            ),
+            Err(BanksClientError::TransactionError(actual_error)) => {
+                assert_eq!(
+                    actual_error, expected_error,
+                    "expected transaction to fail with {:?}, got {:?}",
+                    expected_error, actual_error
+                );
+                Ok(())
+            }
+            Err(other) => panic!(
+                "expected transaction to fail with {:?}, got an unrelated error: {:?}",
+                expected_error, other
+            ),
+        }
+    }
+}
+
+/// The captured side-effects of a transaction that was committed on-chain:
+/// its signature, the program logs it emitted, the compute units it consumed,
+/// and the last `set_return_data` call made during its execution, if any.
+#[derive(Debug, Clone)]
+pub struct TransactionOutcome {
+    pub signature: Signature,
+    pub logs: Vec<String>,
+    pub compute_units_consumed: u64,
+    pub return_data: Option<Vec<u8>>,
+}
+
+/// Assert that a captured transaction's logs contain `substr` in at least one
+/// line, panicking with the full log output otherwise.
+pub fn assert_logs_contain(outcome: &TransactionOutcome, substr: &str) {
+    assert!(
+        outcome.logs.iter().any(|log| log.contains(substr)),
+        "expected transaction logs to contain {:?}, got:\n{:#?}",
+        substr,
+        outcome.logs
+    );
+}
+
+/// Check that every non-signer account referenced by `instructions` is either
+/// `payer`, one of `signers`, the program id of one of `instructions`, or
+/// present in `lookup_table_accounts`. Signers and program ids always resolve
+/// statically regardless of whether they're also covered by a table.
+fn validate_accounts_are_resolvable(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    lookup_table_accounts: &[AddressLookupTableAccount],
+) -> Result<(), BanksClientError> {
+    let mut static_keys: HashSet<Pubkey> = signers.iter().map(|signer| signer.pubkey()).collect();
+    static_keys.insert(*payer);
+    static_keys.extend(instructions.iter().map(|instruction| instruction.program_id));
+
+    let lookup_addresses: HashSet<Pubkey> = lookup_table_accounts
+        .iter()
+        .flat_map(|table| table.addresses.iter().copied())
+        .collect();
+
+    for instruction in instructions {
+        for account in &instruction.accounts {
+            if account.is_signer
+                || static_keys.contains(&account.pubkey)
+                || lookup_addresses.contains(&account.pubkey)
+            {
+                continue;
+            }
+
+            return Err(BanksClientError::ClientError(
+                "Account is neither static (payer/signer/program) nor present in any supplied lookup table",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Prepend the historical default 2,000,000 compute unit limit instruction to
+/// `instructions`.
+fn with_default_compute_limit(instructions: &[Instruction]) -> Vec<Instruction> {
+    let mut all_instructions = Vec::with_capacity(instructions.len() + 1);
+    all_instructions
+        .push(compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(2_000_000));
+    all_instructions.extend_from_slice(instructions);
+    all_instructions
+}
+
+/// A fluent builder that compiles a sequence of instructions into a single
+/// transaction, with an opt-in compute unit limit and priority fee instead of
+/// the hard-coded 2,000,000 CU limit baked into the `_with_default_compute_limit`
+/// methods. Build up the instruction list and signers, then finish with
+/// `.process()` to commit the transaction or `.simulate()` to dry-run it.
+pub struct TransactionBuilder<'a> {
+    simulator: &'a mut ProgramSimulator,
+    instructions: Vec<Instruction>,
+    signers: Vec<&'a dyn Signer>,
+    payer: Option<&'a dyn Signer>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+}
+
+impl<'a> TransactionBuilder<'a> {
+    pub fn new(simulator: &'a mut ProgramSimulator) -> Self {
+        TransactionBuilder {
+            simulator,
+            instructions: Vec::new(),
+            signers: Vec::new(),
+            payer: None,
+            compute_unit_limit: None,
+            compute_unit_price: None,
+        }
+    }
+
+    pub fn add_ix(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// Prepend a `ComputeBudgetInstruction::set_compute_unit_limit` instruction
+    /// with the given limit. Left unset, no limit instruction is added and the
+    /// transaction runs under the cluster default.
+    pub fn compute_unit_limit(mut self, units: u32) -> Self {
+        self.compute_unit_limit = Some(units);
+        self
+    }
+
+    /// Prepend a `ComputeBudgetInstruction::set_compute_unit_price` instruction
+    /// with the given priority fee, in micro-lamports per compute unit.
+    pub fn compute_unit_price(mut self, micro_lamports: u64) -> Self {
+        self.compute_unit_price = Some(micro_lamports);
+        self
+    }
+
+    pub fn payer(mut self, payer: &'a dyn Signer) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+
+    pub fn signer(mut self, signer: &'a dyn Signer) -> Self {
+        self.signers.push(signer);
+        self
+    }
+
+    fn build_instructions(&self) -> Vec<Instruction> {
+        let mut all_instructions = Vec::with_capacity(self.instructions.len() + 2);
+
+        if let Some(units) = self.compute_unit_limit {
+            all_instructions.push(
+                compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(units),
+            );
+        }
+        if let Some(micro_lamports) = self.compute_unit_price {
+            all_instructions.push(
+                compute_budget::ComputeBudgetInstruction::set_compute_unit_price(micro_lamports),
+            );
+        }
+        all_instructions.extend_from_slice(&self.instructions);
+
+        all_instructions
+    }
+
+    /// Build, sign, and send the transaction, committing it on-chain.
+    pub async fn process(self) -> Result<Signature, BanksClientError> {
+        let instructions = self.build_instructions();
+        self.simulator
+            .process_ixs(&instructions, &self.signers, self.payer)
+            .await
+    }
+
+    /// Build, sign, and simulate the transaction without committing it.
+    pub async fn simulate(self) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
+        let instructions = self.build_instructions();
+        self.simulator
+            .simulate_ixs(&instructions, &self.signers, self.payer)
+            .await
+    }
 }
 
 pub fn into_transaction_error<T: Into<anchor_lang::prelude::Error>>(error: T) -> TransactionError {
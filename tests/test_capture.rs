@@ -0,0 +1,47 @@
+use anyhow::Result;
+use solana_program_simulator::{assert_logs_contain, ProgramSimulator};
+use solana_program_test::ProgramTest;
+use solana_sdk::{pubkey::Pubkey, signer::Signer, system_instruction};
+
+#[tokio::test]
+async fn test_process_ix_capturing_returns_logs_and_compute_units() -> Result<()> {
+    // Initialize a ProgramTest environment.
+    let program_test = ProgramTest::default();
+    let mut simulator = ProgramSimulator::start_from_program_test(program_test).await;
+
+    let funded_keypair = simulator.get_funded_keypair().await?;
+    let recipient = Pubkey::new_unique();
+    let instruction = system_instruction::transfer(&funded_keypair.pubkey(), &recipient, 1_000_000);
+
+    let outcome = simulator
+        .process_ix_capturing(instruction, &[&funded_keypair], None)
+        .await?;
+
+    // The system program logs its own invoke/success lines even though it
+    // doesn't call `msg!` itself.
+    assert_logs_contain(&outcome, "success");
+    assert!(outcome.compute_units_consumed > 0);
+
+    let balance = simulator.get_balance(recipient).await?;
+    assert_eq!(balance, 1_000_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "expected transaction logs to contain")]
+async fn test_assert_logs_contain_panics_on_missing_substring() {
+    let program_test = ProgramTest::default();
+    let mut simulator = ProgramSimulator::start_from_program_test(program_test).await;
+
+    let funded_keypair = simulator.get_funded_keypair().await.unwrap();
+    let recipient = Pubkey::new_unique();
+    let instruction = system_instruction::transfer(&funded_keypair.pubkey(), &recipient, 1_000_000);
+
+    let outcome = simulator
+        .process_ix_capturing(instruction, &[&funded_keypair], None)
+        .await
+        .unwrap();
+
+    assert_logs_contain(&outcome, "this substring never appears in the logs");
+}
@@ -0,0 +1,83 @@
+use anyhow::Result;
+use solana_program::program_error::ProgramError;
+use solana_program_simulator::ProgramSimulator;
+use solana_program_test::ProgramTest;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signer::Signer, system_instruction,
+};
+
+fn insufficient_funds_error() -> ProgramError {
+    ProgramError::from(system_instruction::SystemError::ResultWithNegativeLamports)
+}
+
+#[tokio::test]
+async fn test_expect_ix_error_matches_actual_error() -> Result<()> {
+    let program_test = ProgramTest::default();
+    let mut simulator = ProgramSimulator::start_from_program_test(program_test).await;
+
+    let funded_keypair = simulator.get_funded_keypair().await?;
+    let recipient = Pubkey::new_unique();
+
+    // Transfer more than the funded account holds: the system program fails
+    // the instruction with `SystemError::ResultWithNegativeLamports`.
+    let instruction =
+        system_instruction::transfer(&funded_keypair.pubkey(), &recipient, 10 * LAMPORTS_PER_SOL);
+
+    simulator
+        .expect_ix_error(
+            instruction,
+            &[&funded_keypair],
+            None,
+            insufficient_funds_error(),
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[should_panic(expected = "expected transaction to fail")]
+async fn test_expect_ix_error_panics_when_transaction_succeeds() {
+    let program_test = ProgramTest::default();
+    let mut simulator = ProgramSimulator::start_from_program_test(program_test).await;
+
+    let funded_keypair = simulator.get_funded_keypair().await.unwrap();
+    let recipient = Pubkey::new_unique();
+
+    let instruction = system_instruction::transfer(&funded_keypair.pubkey(), &recipient, 1_000_000);
+
+    simulator
+        .expect_ix_error(
+            instruction,
+            &[&funded_keypair],
+            None,
+            insufficient_funds_error(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "expected transaction to fail")]
+async fn test_expect_ix_error_panics_on_error_mismatch() {
+    let program_test = ProgramTest::default();
+    let mut simulator = ProgramSimulator::start_from_program_test(program_test).await;
+
+    let funded_keypair = simulator.get_funded_keypair().await.unwrap();
+    let recipient = Pubkey::new_unique();
+
+    let instruction =
+        system_instruction::transfer(&funded_keypair.pubkey(), &recipient, 10 * LAMPORTS_PER_SOL);
+
+    // The instruction actually fails with `ResultWithNegativeLamports`, not
+    // `InvalidArgument`, so this must panic on the mismatch.
+    simulator
+        .expect_ix_error(
+            instruction,
+            &[&funded_keypair],
+            None,
+            ProgramError::InvalidArgument,
+        )
+        .await
+        .unwrap();
+}
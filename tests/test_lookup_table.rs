@@ -0,0 +1,98 @@
+use anyhow::Result;
+use solana_address_lookup_table_program::instruction::{create_lookup_table, extend_lookup_table};
+use solana_program_simulator::ProgramSimulator;
+use solana_program_test::ProgramTest;
+use solana_sdk::{pubkey::Pubkey, signer::Signer, system_instruction};
+
+#[tokio::test]
+async fn test_v0_transaction_resolves_address_through_lookup_table() -> Result<()> {
+    // Initialize a ProgramTest environment.
+    let program_test = ProgramTest::default();
+    let mut simulator = ProgramSimulator::start_from_program_test(program_test).await;
+
+    // `authority` is passed as both `payer` and the sole entry in `signers`
+    // below, which is the normal way to call this API — it also exercises
+    // that the payer isn't signed for twice when building the v0 transaction.
+    let authority = simulator.get_funded_keypair().await?;
+    let recipient = Pubkey::new_unique();
+
+    // Create a lookup table and extend it with the recipient's address, so
+    // the v0 transaction below can reference it through the table instead of
+    // as a static account key.
+    let recent_slot = simulator.get_clock().await?.slot;
+    let (create_ix, lookup_table_address) =
+        create_lookup_table(authority.pubkey(), authority.pubkey(), recent_slot);
+    simulator
+        .process_ix_with_default_compute_limit(create_ix, &[&authority], Some(&authority))
+        .await?;
+
+    let extend_ix = extend_lookup_table(
+        lookup_table_address,
+        authority.pubkey(),
+        Some(authority.pubkey()),
+        vec![recipient],
+    );
+    simulator
+        .process_ix_with_default_compute_limit(extend_ix, &[&authority], Some(&authority))
+        .await?;
+
+    // A lookup table can't be referenced in the same slot it was last
+    // extended in, so warp forward one slot before using it.
+    let next_slot = simulator.get_clock().await?.slot + 1;
+    simulator.warp_to_slot(next_slot)?;
+
+    let transfer_ix = system_instruction::transfer(&authority.pubkey(), &recipient, 1_000_000);
+
+    simulator
+        .process_ixs_v0_with_default_compute_limit(
+            &[transfer_ix],
+            &[&authority],
+            Some(&authority),
+            &[lookup_table_address],
+        )
+        .await?;
+
+    let balance = simulator.get_balance(recipient).await?;
+    assert_eq!(balance, 1_000_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_v0_transaction_errors_on_unresolved_account() -> Result<()> {
+    let program_test = ProgramTest::default();
+    let mut simulator = ProgramSimulator::start_from_program_test(program_test).await;
+
+    let authority = simulator.get_funded_keypair().await?;
+
+    let recent_slot = simulator.get_clock().await?.slot;
+    let (create_ix, lookup_table_address) =
+        create_lookup_table(authority.pubkey(), authority.pubkey(), recent_slot);
+    simulator
+        .process_ix_with_default_compute_limit(create_ix, &[&authority], Some(&authority))
+        .await?;
+
+    // Note: the table is never extended with `recipient` below, so it isn't
+    // static (not the payer/a signer) and isn't covered by the lookup table.
+    let recipient = Pubkey::new_unique();
+    let next_slot = simulator.get_clock().await?.slot + 1;
+    simulator.warp_to_slot(next_slot)?;
+
+    let transfer_ix = system_instruction::transfer(&authority.pubkey(), &recipient, 1_000_000);
+
+    let result = simulator
+        .process_ixs_v0_with_default_compute_limit(
+            &[transfer_ix],
+            &[&authority],
+            Some(&authority),
+            &[lookup_table_address],
+        )
+        .await;
+
+    assert!(
+        result.is_err(),
+        "expected an unresolved account to error cleanly instead of silently falling back to a static key"
+    );
+
+    Ok(())
+}